@@ -13,6 +13,8 @@ pub fn run() {
             scraper::handle_scraper_auth,
             scraper::show_scraper_window,
             scraper::cancel_scraper_task,
+            scraper::clear_scraper_session,
+            scraper::handle_scraper_parse_error,
             scraper::scraper_log
         ])
         .plugin(tauri_plugin_shell::init())
@@ -39,6 +41,7 @@ pub fn run() {
                     .expect("failed to spawn python backend");
 
                 // 监听 sidecar 输出
+                let sidecar_app = app.handle().clone();
                 tauri::async_runtime::spawn(async move {
                     use tauri_plugin_shell::process::CommandEvent;
                     while let Some(event) = _rx.recv().await {
@@ -51,6 +54,26 @@ pub fn run() {
                             }
                             CommandEvent::Terminated(status) => {
                                 log::error!("[python-backend] terminated with {:?}", status);
+                                // Every source still scraping will otherwise hang
+                                // forever waiting on a backend that's gone, so
+                                // clear their tasks and close their worker
+                                // windows instead of just notifying.
+                                let state = sidecar_app.state::<scraper::ScraperState>();
+                                let stuck_sources: Vec<(String, String)> = {
+                                    let mut tasks = state.tasks.lock().unwrap();
+                                    tasks.drain().map(|(source_id, task)| (source_id, task.window_label)).collect()
+                                };
+                                for (source_id, window_label) in stuck_sources {
+                                    if let Some(win) = sidecar_app.get_webview_window(&window_label) {
+                                        let _ = win.close();
+                                    }
+                                    scraper::emit_scraper_error(
+                                        &sidecar_app,
+                                        &source_id,
+                                        scraper::ScraperErrorReason::BackendTerminated,
+                                        status.code.map(|c| c as u16),
+                                    );
+                                }
                                 break;
                             }
                             _ => {}
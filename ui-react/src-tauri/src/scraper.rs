@@ -1,119 +1,387 @@
 use tauri::{AppHandle, Manager, WebviewWindowBuilder};
 use tauri::Emitter;
-use std::collections::HashSet;
+use rand::RngCore;
+use std::collections::HashMap;
 use std::sync::Mutex;
+use std::time::Instant;
 
-/// Global state to deduplicate scraper results.
-/// Fetch + XHR interceptors can both fire for the same request,
-/// so we only process the first result per source_id.
+/// Bookkeeping for a single source's in-flight scrape.
+pub struct TaskInfo {
+    /// Window label for this source's worker window, e.g.
+    /// `scraper_worker_<source_id>`. Shared by every task generation for the
+    /// same `source_id` — use `nonce`, not this, to identify a specific
+    /// generation.
+    pub window_label: String,
+    pub created_at: Instant,
+    /// Set once `handle_scraped_data` has processed a result for this source.
+    /// A task's `InterceptRule`s are treated as mutually exclusive alternative
+    /// endpoints, not endpoints to merge: the first one to match wins, both to
+    /// absorb duplicate fetch/XHR interceptor fires for that same match and to
+    /// ignore any other rule that also happens to match later in the same
+    /// task. A later match against a *different* rule isn't silently lost —
+    /// see the `handled` branch in `handle_scraped_data`, which surfaces it
+    /// via `scraper_error` instead.
+    pub handled: bool,
+    /// Identity (see `rule_identity`) of the `InterceptRule` that set
+    /// `handled`, kept so a later match can tell "the same rule's other
+    /// interceptor fired" (an expected duplicate, ignored quietly) apart
+    /// from "a different rule matched too" (reported via `scraper_error`
+    /// instead of dropped).
+    pub handled_rule_key: Option<String>,
+    /// Per-task secret injected into the page's interceptors so they can
+    /// prove a command came from the task we spawned, not a third-party
+    /// script on the target page. Never sent to the frontend.
+    pub nonce: String,
+    /// The real API secret, kept server-side and only attached to
+    /// `scraper_result` once a command's nonce has been validated.
+    pub secret_key: String,
+}
+
+/// Generates a cryptographically random, 32-hex-character nonce.
+fn generate_nonce() -> String {
+    let mut bytes = [0u8; 16];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Global state tracking in-flight scraper tasks, one per `source_id`, so that
+/// multiple sources can scrape concurrently without stepping on each other's window.
 pub struct ScraperState {
-    pub handled_results: Mutex<HashSet<String>>,
+    pub tasks: Mutex<HashMap<String, TaskInfo>>,
 }
 
 impl Default for ScraperState {
     fn default() -> Self {
         ScraperState {
-            handled_results: Mutex::new(HashSet::new()),
+            tasks: Mutex::new(HashMap::new()),
         }
     }
 }
 
+/// Rejects a `source_id` that isn't safe to use as a filesystem path segment
+/// or window label, e.g. one containing `..` or path separators.
+fn is_valid_source_id(source_id: &str) -> bool {
+    !source_id.is_empty()
+        && source_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn window_label_for(source_id: &str) -> String {
+    format!("scraper_worker_{}", source_id)
+}
+
+/// Identifies an `InterceptRule` by its full matching criteria, not just
+/// `url_pattern` — two rules can share a `url_pattern` and differ only by
+/// `method` (e.g. GET to list, POST to refresh, on the same endpoint).
+fn rule_identity(url_pattern: &str, method: Option<&str>) -> String {
+    format!("{}::{}", url_pattern, method.unwrap_or_default().to_uppercase())
+}
+
+/// How to keep following a paginated endpoint once a rule's first response
+/// has been captured.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginationRule {
+    /// JSON pointer (RFC 6901), resolved against each page's response body,
+    /// to the field holding the next page's URL or cursor.
+    pub next_field: String,
+    /// Optional JSON pointer to a boolean field that signals more pages
+    /// remain; when absent, pagination stops once `next_field` is empty.
+    #[serde(default)]
+    pub has_more_field: Option<String>,
+    /// Safety cap on the total pages collected for a single match, the first
+    /// page included. Defaults to 50.
+    #[serde(default)]
+    pub max_pages: Option<u32>,
+}
+
+/// One declarative interception target for `push_scraper_task`'s injected
+/// script: which requests to capture, what to pull out of their response,
+/// and whether to keep paging through it.
+#[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InterceptRule {
+    /// Substring the request URL must contain to match this rule.
+    pub url_pattern: String,
+    /// HTTP method to match (case-insensitive). `None` matches any method.
+    #[serde(default)]
+    pub method: Option<String>,
+    /// JSON pointer (RFC 6901) into the response body to extract before
+    /// handing the result to `handle_scraped_data`. `None` keeps the whole body.
+    #[serde(default)]
+    pub extract_pointer: Option<String>,
+    #[serde(default)]
+    pub pagination: Option<PaginationRule>,
+}
+
+/// Per-source WebView data directory (cookies, localStorage, ...), so a
+/// successful interactive login carries over to later automated runs.
+fn session_dir_for(app: &AppHandle, source_id: &str) -> Result<std::path::PathBuf, String> {
+    if !is_valid_source_id(source_id) {
+        return Err(format!("invalid source_id: {}", source_id));
+    }
+    let base = app.path().app_data_dir().map_err(|e| e.to_string())?;
+    Ok(base.join("scraper_sessions").join(source_id))
+}
+
+/// Why a source's scrape failed to produce a result, reported to the
+/// frontend via the `scraper_error` event instead of leaving the task hanging.
+#[derive(serde::Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ScraperErrorReason {
+    /// The watchdog deadline passed before the task was handled, i.e. before
+    /// any result reached `handle_scraped_data`.
+    Timeout,
+    /// A matched response's body could not be parsed as JSON.
+    InvalidJson,
+    /// The worker window was closed before the task was handled.
+    WindowClosed,
+    /// The Python backend sidecar died while sources were still scraping.
+    BackendTerminated,
+    /// A signal arrived after the task's first match had already been
+    /// forwarded: data from a second, *different* `InterceptRule`
+    /// (`handle_scraped_data`), or any late auth-required or parse-failure
+    /// call (`handle_scraper_auth`, `handle_scraper_parse_error`). Rules are
+    /// mutually-exclusive alternative endpoints, not endpoints to merge, so
+    /// that signal is discarded and reported here rather than dropped silently.
+    ExtraRuleDiscarded,
+}
+
+/// Emits a `scraper_error` event for `source_id` so the frontend can surface
+/// an actionable failure instead of waiting on a task that will never resolve.
+pub fn emit_scraper_error(
+    app: &AppHandle,
+    source_id: &str,
+    reason: ScraperErrorReason,
+    status_code: Option<u16>,
+) {
+    println!("[Scraper Debug] emitting scraper_error for {}: {:?}", source_id, reason);
+    let _ = app.emit("scraper_error", serde_json::json!({
+        "sourceId": source_id,
+        "reason": reason,
+        "statusCode": status_code,
+    }));
+}
+
 #[tauri::command]
-pub async fn scraper_log(message: String) -> Result<(), String> {
+pub async fn scraper_log(
+    app: AppHandle,
+    source_id: String,
+    nonce: String,
+    message: String,
+) -> Result<(), String> {
+    if !validate_nonce(&app, &source_id, &nonce) {
+        return Err("invalid nonce".to_string());
+    }
     println!("[Scraper JS Debug] {}", message);
     Ok(())
 }
 
+/// Checks that `nonce` matches the one handed to the task currently running
+/// for `source_id`, so only the page we injected it into can call back in.
+fn validate_nonce(app: &AppHandle, source_id: &str, nonce: &str) -> bool {
+    let state = app.state::<ScraperState>();
+    let tasks = state.tasks.lock().unwrap();
+    tasks.get(source_id).map(|task| task.nonce == nonce).unwrap_or(false)
+}
+
+/// Default watchdog timeout when `push_scraper_task` isn't given one.
+const DEFAULT_TASK_TIMEOUT_SECS: u64 = 30;
+
 #[tauri::command]
 pub async fn push_scraper_task(
     app: AppHandle,
     source_id: String,
     url: String,
     inject_script: String,
-    intercept_api: String,
+    intercept_rules: Vec<InterceptRule>,
     secret_key: String,
+    timeout_secs: Option<u64>,
 ) -> Result<(), String> {
     println!("[Scraper Debug] push_scraper_task called for source_id: {}, url: {}", source_id, url);
-    
-    // Clear any previous dedup record for this source so the new task's result is processed
+
+    if !is_valid_source_id(&source_id) {
+        return Err(format!("invalid source_id: {}", source_id));
+    }
+
+    let window_label = window_label_for(&source_id);
+    let nonce = generate_nonce();
+
+    // If this source already has a task running, close its window before
+    // starting the new one so a slow login on another source is unaffected.
     {
         let state = app.state::<ScraperState>();
-        let mut handled = state.handled_results.lock().unwrap();
-        handled.remove(&source_id);
+        let mut tasks = state.tasks.lock().unwrap();
+        if let Some(previous) = tasks.remove(&source_id) {
+            if let Some(win) = app.get_webview_window(&previous.window_label) {
+                println!("[Scraper Debug] closing existing window for source_id: {}", source_id);
+                let _ = win.close();
+            }
+        }
+        tasks.insert(
+            source_id.clone(),
+            TaskInfo {
+                window_label: window_label.clone(),
+                created_at: Instant::now(),
+                handled: false,
+                handled_rule_key: None,
+                nonce: nonce.clone(),
+                secret_key,
+            },
+        );
     }
-    
+
+    // Only the nonce is injected into the page — never the real secret_key —
+    // so a third-party script on the target site can't read it or spoof calls.
+    let source_id_js = serde_json::to_string(&source_id).map_err(|e| e.to_string())?;
+    let nonce_js = serde_json::to_string(&nonce).map_err(|e| e.to_string())?;
+    let intercept_rules_js = serde_json::to_string(&intercept_rules).map_err(|e| e.to_string())?;
+
     let final_script = format!(
         r#"
         (function() {{
+            const __SOURCE_ID__ = {source_id_js};
+            const __NONCE__ = {nonce_js};
+            const RULES = {intercept_rules_js};
             function logDebug(msg) {{
                 try {{
-                    window.__TAURI_INTERNALS__.invoke('scraper_log', {{ message: msg }});
+                    window.__TAURI_INTERNALS__.invoke('scraper_log', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, message: msg }});
                 }} catch(e) {{}}
             }}
             logDebug('Scraper initialization started');
             // Resource blocker
             const blockExtensions = ['.png', '.jpg', '.jpeg', '.gif', '.svg', '.webp', '.woff', '.woff2', '.ttf'];
-            
+
+            function matchRule(reqUrl, method) {{
+                const upperMethod = (method || 'GET').toUpperCase();
+                return RULES.find(rule => reqUrl.includes(rule.urlPattern) && (!rule.method || rule.method.toUpperCase() === upperMethod));
+            }}
+
+            // Resolves an RFC 6901 JSON pointer against `obj`; a missing/empty pointer returns `obj` itself.
+            function resolvePointer(obj, pointer) {{
+                if (!pointer) return obj;
+                const parts = pointer.split('/').filter(p => p.length > 0).map(p => p.replace(/~1/g, '/').replace(/~0/g, '~'));
+                let cur = obj;
+                for (const part of parts) {{
+                    if (cur == null) return undefined;
+                    cur = cur[part];
+                }}
+                return cur;
+            }}
+
+            function mergePages(pages) {{
+                return pages.every(page => Array.isArray(page)) ? pages.flat() : pages;
+            }}
+
+            async function followPagination(rule, firstBody, firstExtracted) {{
+                const pages = [firstExtracted];
+                const maxPages = rule.pagination.maxPages || 50;
+                let nextUrl = resolvePointer(firstBody, rule.pagination.nextField);
+                let hasMore = rule.pagination.hasMoreField ? !!resolvePointer(firstBody, rule.pagination.hasMoreField) : !!nextUrl;
+
+                while (nextUrl && hasMore && pages.length < maxPages) {{
+                    logDebug('Following pagination to: ' + nextUrl);
+                    const response = await originalFetch(nextUrl);
+                    if (response.status === 401 || response.status === 403) {{
+                        window.__TAURI_INTERNALS__.invoke('handle_scraper_auth', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, targetUrl: nextUrl }});
+                        return;
+                    }}
+                    let body;
+                    try {{
+                        body = await response.json();
+                    }} catch (e) {{
+                        logDebug('Failed to capture paginated JSON: ' + e);
+                        window.__TAURI_INTERNALS__.invoke('handle_scraper_parse_error', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, statusCode: response.status }});
+                        return;
+                    }}
+                    pages.push(resolvePointer(body, rule.extractPointer));
+                    nextUrl = resolvePointer(body, rule.pagination.nextField);
+                    hasMore = rule.pagination.hasMoreField ? !!resolvePointer(body, rule.pagination.hasMoreField) : !!nextUrl;
+                }}
+
+                logDebug('Pagination exhausted after ' + pages.length + ' page(s), sending merged scraped data');
+                window.__TAURI_INTERNALS__.invoke('handle_scraped_data', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, rulePattern: rule.urlPattern, ruleMethod: rule.method || null, data: mergePages(pages) }});
+            }}
+
+            async function handleMatchedResponse(rule, reqUrl, response) {{
+                if (response.status === 401 || response.status === 403) {{
+                    logDebug('Auth required triggered');
+                    window.__TAURI_INTERNALS__.invoke('handle_scraper_auth', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, targetUrl: reqUrl }});
+                    return;
+                }}
+                let body;
+                try {{
+                    body = await response.clone().json();
+                }} catch (e) {{
+                    logDebug('Failed to capture JSON: ' + e);
+                    console.error('Failed to capture JSON:', e);
+                    window.__TAURI_INTERNALS__.invoke('handle_scraper_parse_error', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, statusCode: response.status }});
+                    return;
+                }}
+                const extracted = resolvePointer(body, rule.extractPointer);
+                if (rule.pagination) {{
+                    logDebug('JSON parse successful, following pagination');
+                    await followPagination(rule, body, extracted);
+                }} else {{
+                    logDebug('JSON parse successful, sending scraped data');
+                    window.__TAURI_INTERNALS__.invoke('handle_scraped_data', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, rulePattern: rule.urlPattern, ruleMethod: rule.method || null, data: extracted }});
+                }}
+            }}
+
             const originalFetch = window.fetch;
             window.fetch = async function(...args) {{
                 const reqUrl = (typeof args[0] === 'string' ? args[0] : args[0]?.url) || '';
-                
+                const method = args[1]?.method || (typeof args[0] === 'object' ? args[0]?.method : undefined) || 'GET';
+
                 // Block static resources
                 if (blockExtensions.some(ext => reqUrl.toLowerCase().includes(ext))) {{
                     return new Response('', {{ status: 200, statusText: 'Blocked' }});
                 }}
-                
-                // Intercept Target API
-                if (reqUrl.includes('{}')) {{
-                    logDebug('Matched intercept API: ' + reqUrl);
+
+                // Intercept Target API(s)
+                const rule = matchRule(reqUrl, method);
+                if (rule) {{
+                    logDebug('Matched intercept rule: ' + reqUrl);
                     const response = await originalFetch.apply(this, args);
                     logDebug('Response received with status: ' + response.status);
-                    if (response.status === 401 || response.status === 403) {{
-                        logDebug('Auth required triggered');
-                        window.__TAURI_INTERNALS__.invoke('handle_scraper_auth', {{ sourceId: '{}', targetUrl: reqUrl }});
-                    }} else {{
-                        const cloneRes = response.clone();
-                        cloneRes.json().then(data => {{
-                            logDebug('JSON parse successful, sending scraped data');
-                            window.__TAURI_INTERNALS__.invoke('handle_scraped_data', {{ 
-                                sourceId: '{}', 
-                                secretKey: '{}',
-                                data: data 
-                            }});
-                        }}).catch(e => {{
-                            logDebug('Failed to capture JSON: ' + e);
-                            console.error('Failed to capture JSON:', e);
-                        }});
-                    }}
+                    handleMatchedResponse(rule, reqUrl, response).catch(e => {{
+                        console.error('Error handling matched response:', e);
+                    }});
                     return response;
                 }}
-                
+
                 return originalFetch.apply(this, args);
             }};
-            
+
             // XHR overrides (Optional, if target uses XHR instead of Fetch)
             const originalXhrOpen = XMLHttpRequest.prototype.open;
             XMLHttpRequest.prototype.open = function(method, xUrl, ...rest) {{
                 this._url = xUrl;
+                this._method = method;
                 return originalXhrOpen.call(this, method, xUrl, ...rest);
             }};
-            
+
             const originalXhrSend = XMLHttpRequest.prototype.send;
             XMLHttpRequest.prototype.send = function(body) {{
                 this.addEventListener('load', function() {{
-                    if (this._url && this._url.includes('{}')) {{
-                         if (this.status === 401 || this.status === 403) {{
-                             window.__TAURI_INTERNALS__.invoke('handle_scraper_auth', {{ sourceId: '{}', targetUrl: this._url }});
-                         }} else {{
-                             try {{
-                                 const data = JSON.parse(this.responseText);
-                                 window.__TAURI_INTERNALS__.invoke('handle_scraped_data', {{ 
-                                     sourceId: '{}', 
-                                     secretKey: '{}',
-                                     data: data 
-                                 }});
-                             }} catch(e) {{}}
-                         }}
+                    const rule = this._url && matchRule(this._url, this._method);
+                    if (!rule) return;
+                    if (this.status === 401 || this.status === 403) {{
+                        window.__TAURI_INTERNALS__.invoke('handle_scraper_auth', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, targetUrl: this._url }});
+                        return;
+                    }}
+                    let data;
+                    try {{
+                        data = JSON.parse(this.responseText);
+                    }} catch(e) {{
+                        logDebug('Failed to capture JSON: ' + e);
+                        window.__TAURI_INTERNALS__.invoke('handle_scraper_parse_error', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, statusCode: this.status }});
+                        return;
+                    }}
+                    const extracted = resolvePointer(data, rule.extractPointer);
+                    if (rule.pagination) {{
+                        followPagination(rule, data, extracted).catch(e => console.error('Error following pagination:', e));
+                    }} else {{
+                        window.__TAURI_INTERNALS__.invoke('handle_scraped_data', {{ sourceId: __SOURCE_ID__, nonce: __NONCE__, rulePattern: rule.urlPattern, ruleMethod: rule.method || null, data: extracted }});
                     }}
                 }});
                 return originalXhrSend.call(this, body);
@@ -132,7 +400,7 @@ pub async fn push_scraper_task(
                     }}
                 }}
             }});
-            
+
             // Start observing as soon as possible
             if (document.documentElement) {{
                 observer.observe(document.documentElement, {{ childList: true, subtree: true }});
@@ -141,33 +409,27 @@ pub async fn push_scraper_task(
                     observer.observe(document.documentElement, {{ childList: true, subtree: true }});
                 }});
             }}
-            
+
             // User Injected Script
             try {{
                 logDebug('Executing user inject script');
-                {}
+                {inject_script}
                 logDebug('User inject script executed cleanly');
-            }} catch(e) {{ 
+            }} catch(e) {{
                 logDebug('Inject script error: ' + String(e));
-                console.error('Inject script error:', e); 
+                console.error('Inject script error:', e);
             }}
         }})();
         "#,
-        intercept_api, source_id, source_id, secret_key, 
-        intercept_api, source_id, source_id, secret_key,
-        inject_script
     );
 
-    // If a scraper window already exists, close it to avoid state pollution and cleanly re-inject
-    if let Some(win) = app.get_webview_window("scraper_worker") {
-        println!("[Scraper Debug] closing existing scraper_worker");
-        let _ = win.close();
-    }
+    let session_dir = session_dir_for(&app, &source_id)?;
+    std::fs::create_dir_all(&session_dir).map_err(|e| e.to_string())?;
 
     println!("[Scraper Debug] building new WebviewWindow for url: {}", url);
-    let _webview = tauri::WebviewWindowBuilder::new(
+    let _webview = WebviewWindowBuilder::new(
         &app,
-        "scraper_worker",
+        &window_label,
         tauri::WebviewUrl::External(url.parse().unwrap())
     )
     .title("Background Worker")
@@ -176,15 +438,69 @@ pub async fn push_scraper_task(
     .visible(true)
     .inner_size(10.0, 10.0)
     .position(-10000.0, -10000.0)
+    // Bind this source's persistent cookie/localStorage partition so a
+    // successful login survives across tasks instead of dying with the window.
+    .data_directory(session_dir)
     .initialization_script(&final_script)
     .build()
     .map_err(|e| {
         println!("[Scraper Debug] failed to build webview: {}", e);
         e.to_string()
     })?;
-    
+
     println!("[Scraper Debug] WebviewWindow built successfully");
 
+    // If the user closes the worker window before the task produced a
+    // result, report it instead of leaving the caller waiting forever.
+    let close_app = app.clone();
+    let close_source_id = source_id.clone();
+    let close_nonce = nonce.clone();
+    _webview.on_window_event(move |event| {
+        if let tauri::WindowEvent::CloseRequested { .. } = event {
+            let needs_error = {
+                let state = close_app.state::<ScraperState>();
+                let mut tasks = state.tasks.lock().unwrap();
+                match tasks.get(&close_source_id) {
+                    Some(task) if task.nonce == close_nonce && !task.handled => {
+                        tasks.remove(&close_source_id);
+                        true
+                    }
+                    _ => false,
+                }
+            };
+            if needs_error {
+                emit_scraper_error(&close_app, &close_source_id, ScraperErrorReason::WindowClosed, None);
+            }
+        }
+    });
+
+    // Watchdog: if no InterceptRule ever matches, the page's interceptors
+    // never fire and the window would otherwise linger indefinitely.
+    let timeout_secs = timeout_secs.unwrap_or(DEFAULT_TASK_TIMEOUT_SECS);
+    let watchdog_app = app.clone();
+    let watchdog_source_id = source_id.clone();
+    let watchdog_nonce = nonce.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_secs(timeout_secs)).await;
+        let timed_out_label = {
+            let state = watchdog_app.state::<ScraperState>();
+            let mut tasks = state.tasks.lock().unwrap();
+            match tasks.get(&watchdog_source_id) {
+                Some(task) if task.nonce == watchdog_nonce && !task.handled => {
+                    tasks.remove(&watchdog_source_id).map(|task| task.window_label)
+                }
+                _ => None,
+            }
+        };
+        if let Some(label) = timed_out_label {
+            println!("[Scraper Debug] watchdog timeout for source_id: {}", watchdog_source_id);
+            if let Some(win) = watchdog_app.get_webview_window(&label) {
+                let _ = win.close();
+            }
+            emit_scraper_error(&watchdog_app, &watchdog_source_id, ScraperErrorReason::Timeout, None);
+        }
+    });
+
     Ok(())
 }
 
@@ -192,31 +508,59 @@ pub async fn push_scraper_task(
 pub async fn handle_scraped_data(
     app: AppHandle,
     source_id: String,
-    secret_key: String,
+    nonce: String,
+    rule_pattern: String,
+    rule_method: Option<String>,
     data: serde_json::Value,
 ) -> Result<(), String> {
     println!("[Scraper Debug] handle_scraped_data called for source_id: {}", source_id);
-    
-    // Deduplicate: only emit the first result for each source_id.
-    // Fetch and XHR interceptors may both fire, causing duplicate invocations.
-    {
+
+    // Deduplicate: only emit the first result for each source_id. Fetch and
+    // XHR interceptors may both fire for the same rule, causing duplicate
+    // invocations for it; those are ignored quietly. A second *different*
+    // rule matching is a distinct case — see the `ExtraRuleDiscarded` arm.
+    let rule_key = rule_identity(&rule_pattern, rule_method.as_deref());
+    let (window_label, secret_key) = {
         let state = app.state::<ScraperState>();
-        let mut handled = state.handled_results.lock().unwrap();
-        if handled.contains(&source_id) {
-            println!("[Scraper Debug] Duplicate handle_scraped_data for {}, ignoring.", source_id);
-            return Ok(());
+        let mut tasks = state.tasks.lock().unwrap();
+        match tasks.get_mut(&source_id) {
+            Some(task) if task.nonce != nonce => {
+                println!("[Scraper Debug] Rejecting handle_scraped_data for {}: nonce mismatch", source_id);
+                return Err("invalid nonce".to_string());
+            }
+            Some(task) if task.handled => {
+                let is_extra_rule = task.handled_rule_key.as_deref() != Some(rule_key.as_str());
+                println!(
+                    "[Scraper Debug] Duplicate handle_scraped_data for {} (rule: {}), ignoring.",
+                    source_id, rule_key
+                );
+                if is_extra_rule {
+                    emit_scraper_error(&app, &source_id, ScraperErrorReason::ExtraRuleDiscarded, None);
+                }
+                return Ok(());
+            }
+            Some(task) => {
+                task.handled = true;
+                task.handled_rule_key = Some(rule_key);
+                (task.window_label.clone(), task.secret_key.clone())
+            }
+            None => {
+                println!("[Scraper Debug] handle_scraped_data for unknown source_id: {}, ignoring.", source_id);
+                return Ok(());
+            }
         }
-        handled.insert(source_id.clone());
-    }
-    
+    };
+
+    // The real secret is attached here, server-side, only after the nonce
+    // proved the call came from the task we spawned.
     app.emit("scraper_result", serde_json::json!({
         "sourceId": source_id,
         "secretKey": secret_key,
         "data": data
     })).map_err(|e| e.to_string())?;
-    
-    // Close the scraper window since task is done
-    if let Some(win) = app.get_webview_window("scraper_worker") {
+
+    // Close the source's window since its task is done
+    if let Some(win) = app.get_webview_window(&window_label) {
         let _ = win.close();
     }
     Ok(())
@@ -226,17 +570,43 @@ pub async fn handle_scraped_data(
 pub async fn handle_scraper_auth(
     app: AppHandle,
     source_id: String,
+    nonce: String,
     target_url: String,
 ) -> Result<(), String> {
     println!("[Scraper Debug] handle_scraper_auth called for source_id: {}, target_url: {}", source_id, target_url);
 
+    // Resolve the window to show in the same lock as the nonce/handled
+    // checks: a second rule's fetch/XHR for this task can still come back
+    // as a 401/403 after another rule already completed the task, and that
+    // stale signal shouldn't reopen a window whose task is done.
+    let window_label = {
+        let state = app.state::<ScraperState>();
+        let tasks = state.tasks.lock().unwrap();
+        match tasks.get(&source_id) {
+            Some(task) if task.nonce != nonce => {
+                println!("[Scraper Debug] Rejecting handle_scraper_auth for {}: nonce mismatch", source_id);
+                return Err("invalid nonce".to_string());
+            }
+            Some(task) if task.handled => {
+                println!("[Scraper Debug] handle_scraper_auth for {} arrived after task was already handled, ignoring.", source_id);
+                emit_scraper_error(&app, &source_id, ScraperErrorReason::ExtraRuleDiscarded, None);
+                return Ok(());
+            }
+            Some(task) => task.window_label.clone(),
+            None => {
+                println!("[Scraper Debug] Rejecting handle_scraper_auth for {}: nonce mismatch", source_id);
+                return Err("invalid nonce".to_string());
+            }
+        }
+    };
+
     app.emit("scraper_auth_required", serde_json::json!({
         "sourceId": source_id,
         "targetUrl": target_url
     })).map_err(|e| e.to_string())?;
-    
-    // Show the window to allow user to log in
-    if let Some(win) = app.get_webview_window("scraper_worker") {
+
+    // Show only this source's window to allow the user to log in
+    if let Some(win) = app.get_webview_window(&window_label) {
         let _ = win.set_decorations(true);
         let _ = win.set_size(tauri::Size::Logical(tauri::LogicalSize::new(800.0, 600.0)));
         let _ = win.center();
@@ -246,17 +616,81 @@ pub async fn handle_scraper_auth(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn handle_scraper_parse_error(
+    app: AppHandle,
+    source_id: String,
+    nonce: String,
+    status_code: Option<u16>,
+) -> Result<(), String> {
+    println!("[Scraper Debug] handle_scraper_parse_error called for source_id: {}", source_id);
+
+    // A second rule's fetch/XHR for this task can still fail to parse after
+    // another rule already completed the task; don't tear down the
+    // already-finished task's state or re-close its already-closed window
+    // for that stale signal.
+    let window_label = {
+        let state = app.state::<ScraperState>();
+        let mut tasks = state.tasks.lock().unwrap();
+        match tasks.get(&source_id) {
+            Some(task) if task.nonce != nonce => {
+                println!("[Scraper Debug] Rejecting handle_scraper_parse_error for {}: nonce mismatch", source_id);
+                return Err("invalid nonce".to_string());
+            }
+            Some(task) if task.handled => {
+                println!("[Scraper Debug] handle_scraper_parse_error for {} arrived after task was already handled, ignoring.", source_id);
+                emit_scraper_error(&app, &source_id, ScraperErrorReason::ExtraRuleDiscarded, None);
+                return Ok(());
+            }
+            Some(_) => tasks.remove(&source_id).map(|task| task.window_label),
+            None => {
+                println!("[Scraper Debug] Rejecting handle_scraper_parse_error for {}: nonce mismatch", source_id);
+                return Err("invalid nonce".to_string());
+            }
+        }
+    };
+    if let Some(window_label) = window_label {
+        if let Some(win) = app.get_webview_window(&window_label) {
+            let _ = win.close();
+        }
+    }
+
+    emit_scraper_error(&app, &source_id, ScraperErrorReason::InvalidJson, status_code);
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn show_scraper_window(
     app: AppHandle,
+    source_id: String,
 ) -> Result<(), String> {
-    println!("[Scraper Debug] show_scraper_window called");
-    if let Some(win) = app.get_webview_window("scraper_worker") {
-        let _ = win.set_decorations(true);
-        let _ = win.set_size(tauri::Size::Logical(tauri::LogicalSize::new(800.0, 600.0)));
-        let _ = win.center();
-        let _ = win.show();
-        let _ = win.set_focus();
+    println!("[Scraper Debug] show_scraper_window called for source_id: {}", source_id);
+    let window_label = {
+        let state = app.state::<ScraperState>();
+        let tasks = state.tasks.lock().unwrap();
+        tasks.get(&source_id).map(|task| task.window_label.clone())
+    };
+    if let Some(window_label) = window_label {
+        if let Some(win) = app.get_webview_window(&window_label) {
+            let _ = win.set_decorations(true);
+            let _ = win.set_size(tauri::Size::Logical(tauri::LogicalSize::new(800.0, 600.0)));
+            let _ = win.center();
+            let _ = win.show();
+            let _ = win.set_focus();
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn clear_scraper_session(
+    app: AppHandle,
+    source_id: String,
+) -> Result<(), String> {
+    println!("[Scraper Debug] clear_scraper_session called for source_id: {}", source_id);
+    let session_dir = session_dir_for(&app, &source_id)?;
+    if session_dir.exists() {
+        std::fs::remove_dir_all(&session_dir).map_err(|e| e.to_string())?;
     }
     Ok(())
 }
@@ -264,10 +698,18 @@ pub async fn show_scraper_window(
 #[tauri::command]
 pub async fn cancel_scraper_task(
     app: AppHandle,
+    source_id: String,
 ) -> Result<(), String> {
-    println!("[Scraper Debug] cancel_scraper_task called");
-    if let Some(win) = app.get_webview_window("scraper_worker") {
-        let _ = win.close();
+    println!("[Scraper Debug] cancel_scraper_task called for source_id: {}", source_id);
+    let task = {
+        let state = app.state::<ScraperState>();
+        let mut tasks = state.tasks.lock().unwrap();
+        tasks.remove(&source_id)
+    };
+    if let Some(task) = task {
+        if let Some(win) = app.get_webview_window(&task.window_label) {
+            let _ = win.close();
+        }
     }
     Ok(())
 }